@@ -0,0 +1,41 @@
+//! Module housing the packet-processing path: deriving the "other side" of an `AddressPortPair`
+//! for host resolution, the local side used to attribute a connection to its process, and feeding
+//! the standalone neighbor cache as ARP/NDP traffic is observed.
+
+use std::net::IpAddr;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::networking::types::trans_protocol::TransProtocol;
+use crate::utils::types::timestamp::Timestamp;
+use crate::InfoTraffic;
+
+/// Returns the remote address of `key` given the direction the traffic is flowing in, i.e. the
+/// address that is *not* the local host's.
+pub fn get_address_to_lookup(key: &AddressPortPair, traffic_direction: TrafficDirection) -> IpAddr {
+    match traffic_direction {
+        TrafficDirection::Outgoing => key.address2,
+        TrafficDirection::Incoming => key.address1,
+    }
+}
+
+/// Returns the `(transport protocol, local port)` of `key` given the direction the traffic is
+/// flowing in, used to attribute the connection to the owning local process. The protocol is
+/// part of the key because TCP and UDP each have their own port namespace.
+pub fn get_local_port(
+    key: &AddressPortPair,
+    traffic_direction: TrafficDirection,
+) -> (TransProtocol, u16) {
+    let port = match traffic_direction {
+        TrafficDirection::Outgoing => key.port1,
+        TrafficDirection::Incoming => key.port2,
+    };
+    (key.protocol, port)
+}
+
+/// Folds an observed ARP reply or NDP neighbor advertisement into `info_traffic`'s neighbor
+/// cache. Called by the link-layer packet parser whenever such a message is decoded,
+/// independently of the address:port connection map.
+pub fn record_arp_sighting(info_traffic: &mut InfoTraffic, ip: IpAddr, mac: String, now: Timestamp) {
+    info_traffic.neighbors.observe(ip, mac, now);
+}
@@ -0,0 +1,84 @@
+//! Module defining `SearchParameters`, the report search bar state used to filter the entries
+//! returned by [`get_searched_entries`](crate::report::get_report_entries::get_searched_entries).
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::host::Host;
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+
+/// Search bar state for the address:port report.
+#[derive(Clone, Debug, Default)]
+pub struct SearchParameters {
+    /// Filters entries whose resolved remote host's AS number or AS name matches this query.
+    pub asn: String,
+}
+
+impl SearchParameters {
+    /// Returns `true` if `key`/`value` satisfy every active search constraint.
+    pub fn match_entry(
+        &self,
+        _key: &AddressPortPair,
+        _value: &InfoAddressPortPair,
+        r_dns_host: Option<&(String, Host)>,
+        _is_favorite: bool,
+    ) -> bool {
+        self.matches_asn(r_dns_host)
+    }
+
+    fn matches_asn(&self, r_dns_host: Option<&(String, Host)>) -> bool {
+        if self.asn.is_empty() {
+            return true;
+        }
+        r_dns_host
+            .map(|(_, host)| host.asn.matches(&self.asn))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networking::types::asn::Asn;
+
+    fn host_with_asn(number: u32, name: &str) -> (String, Host) {
+        (
+            "example.com".to_string(),
+            Host {
+                domain: "example.com".to_string(),
+                asn: Asn {
+                    number,
+                    name: name.to_string(),
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let params = SearchParameters::default();
+        assert!(params.matches_asn(None));
+        assert!(params.matches_asn(Some(&host_with_asn(15169, "GOOGLE"))));
+    }
+
+    #[test]
+    fn test_matches_asn_by_number_and_name() {
+        let by_number = SearchParameters {
+            asn: "15169".to_string(),
+        };
+        let by_name = SearchParameters {
+            asn: "google".to_string(),
+        };
+        let resolved = host_with_asn(15169, "GOOGLE");
+
+        assert!(by_number.matches_asn(Some(&resolved)));
+        assert!(by_name.matches_asn(Some(&resolved)));
+        assert!(!by_number.matches_asn(None));
+    }
+
+    #[test]
+    fn test_non_matching_asn_is_filtered_out() {
+        let params = SearchParameters {
+            asn: "16509".to_string(),
+        };
+        assert!(!params.matches_asn(Some(&host_with_asn(15169, "GOOGLE"))));
+    }
+}
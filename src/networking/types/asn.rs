@@ -0,0 +1,276 @@
+//! Module defining the `Asn` struct and the longest-prefix-match table used to resolve an
+//! `IpAddr` to its origin autonomous system.
+
+use std::net::IpAddr;
+
+/// Autonomous system that originates a given IP prefix, as resolved from the bundled
+/// IP-to-ASN dataset.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct Asn {
+    /// Autonomous system number (e.g. `15169`).
+    pub number: u32,
+    /// Autonomous system name (e.g. `GOOGLE`).
+    pub name: String,
+}
+
+impl Asn {
+    /// Returns `true` if `query` (case-insensitive) matches this AS number or AS name,
+    /// used by the report search bar.
+    pub fn matches(&self, query: &str) -> bool {
+        if query.is_empty() {
+            return false;
+        }
+        if let Ok(number) = query.parse::<u32>() {
+            if number == self.number {
+                return true;
+            }
+        }
+        self.name.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// A single IPv4 prefix entry of the longest-prefix-match table.
+#[derive(Clone, Copy, Debug)]
+struct Ipv4Entry {
+    addr: [u8; 4],
+    pfxlen: u8,
+}
+
+/// A single IPv6 prefix entry of the longest-prefix-match table.
+#[derive(Clone, Copy, Debug)]
+struct Ipv6Entry {
+    addr: [u8; 16],
+    pfxlen: u8,
+}
+
+/// Compact in-memory longest-prefix-match table resolving an [`IpAddr`] to its origin [`Asn`],
+/// loaded once at startup from a bundled IP-to-ASN dataset (or an MRT/BGP table dump).
+///
+/// Entries are bucketed by prefix length so that a lookup only needs to probe from the
+/// longest prefix length downward until a matching prefix is found, keeping memory usage and
+/// lookup cost close to that of a real routing table.
+#[derive(Default, Debug)]
+pub struct AsnTable {
+    ipv4_buckets: Vec<Vec<(Ipv4Entry, Asn)>>,
+    ipv6_buckets: Vec<Vec<(Ipv6Entry, Asn)>>,
+}
+
+/// Bundled sample IP-to-ASN dataset, one `prefix/pfxlen,asn,name` row per line — the format a
+/// flattened MRT/BGP table dump would be reduced to (one origin AS per prefix). This is a small
+/// built-in seed covering a handful of well-known networks, not a full routing table; a real
+/// deployment is expected to load a complete dataset the same way, via [`AsnTable::parse_dataset`].
+const BUNDLED_DATASET: &str = "\
+8.8.8.0/24,15169,GOOGLE
+8.8.4.0/24,15169,GOOGLE
+1.1.1.0/24,13335,CLOUDFLARE
+1.0.0.0/24,13335,CLOUDFLARE
+9.9.9.0/24,19281,QUAD9
+2001:4860::/32,15169,GOOGLE
+2606:4700::/32,13335,CLOUDFLARE
+";
+
+impl AsnTable {
+    /// Builds an empty table; entries are added with [`AsnTable::insert_ipv4`] and
+    /// [`AsnTable::insert_ipv6`] while parsing the bundled dataset.
+    pub fn new() -> Self {
+        Self {
+            ipv4_buckets: vec![Vec::new(); 33],
+            ipv6_buckets: vec![Vec::new(); 129],
+        }
+    }
+
+    /// Builds a table pre-loaded from [`BUNDLED_DATASET`], the in-memory sample IP-to-ASN
+    /// dataset shipped with this crate.
+    pub fn load_bundled() -> Self {
+        let mut table = Self::new();
+        table.parse_dataset(BUNDLED_DATASET);
+        table
+    }
+
+    /// Parses `dataset`, a newline-separated list of `prefix/pfxlen,asn,name` rows, inserting
+    /// every well-formed row and silently skipping malformed ones.
+    pub fn parse_dataset(&mut self, dataset: &str) {
+        for line in dataset.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((prefix, rest)) = line.split_once(',') else {
+                continue;
+            };
+            let Some((number_str, name)) = rest.split_once(',') else {
+                continue;
+            };
+            let Some((addr_str, pfxlen_str)) = prefix.split_once('/') else {
+                continue;
+            };
+            let (Ok(number), Ok(pfxlen), Ok(addr)) = (
+                number_str.parse::<u32>(),
+                pfxlen_str.parse::<u8>(),
+                addr_str.parse::<IpAddr>(),
+            ) else {
+                continue;
+            };
+            let asn = Asn {
+                number,
+                name: name.to_string(),
+            };
+            match addr {
+                IpAddr::V4(addr) if pfxlen <= 32 => self.insert_ipv4(addr.octets(), pfxlen, asn),
+                IpAddr::V6(addr) if pfxlen <= 128 => self.insert_ipv6(addr.octets(), pfxlen, asn),
+                _ => {}
+            }
+        }
+    }
+
+    pub fn insert_ipv4(&mut self, addr: [u8; 4], pfxlen: u8, asn: Asn) {
+        self.ipv4_buckets[pfxlen as usize].push((Ipv4Entry { addr, pfxlen }, asn));
+    }
+
+    pub fn insert_ipv6(&mut self, addr: [u8; 16], pfxlen: u8, asn: Asn) {
+        self.ipv6_buckets[pfxlen as usize].push((Ipv6Entry { addr, pfxlen }, asn));
+    }
+
+    /// Resolves `ip` to its origin ASN, probing from the longest prefix length downward
+    /// until a matching prefix is found.
+    pub fn lookup(&self, ip: IpAddr) -> Option<Asn> {
+        match ip {
+            IpAddr::V4(ip) => self.lookup_ipv4(ip.octets()),
+            IpAddr::V6(ip) => self.lookup_ipv6(ip.octets()),
+        }
+    }
+
+    fn lookup_ipv4(&self, octets: [u8; 4]) -> Option<Asn> {
+        for pfxlen in (0..=32u8).rev() {
+            for (entry, asn) in &self.ipv4_buckets[pfxlen as usize] {
+                if prefix_matches(&octets, &entry.addr, entry.pfxlen) {
+                    return Some(asn.clone());
+                }
+            }
+        }
+        None
+    }
+
+    fn lookup_ipv6(&self, octets: [u8; 16]) -> Option<Asn> {
+        for pfxlen in (0..=128u8).rev() {
+            for (entry, asn) in &self.ipv6_buckets[pfxlen as usize] {
+                if prefix_matches(&octets, &entry.addr, entry.pfxlen) {
+                    return Some(asn.clone());
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns `true` if the first `pfxlen` bits of `addr` and `prefix` are equal.
+fn prefix_matches<const N: usize>(addr: &[u8; N], prefix: &[u8; N], pfxlen: u8) -> bool {
+    let full_bytes = (pfxlen / 8) as usize;
+    let remaining_bits = pfxlen % 8;
+
+    if addr[..full_bytes] != prefix[..full_bytes] {
+        return false;
+    }
+
+    if remaining_bits == 0 {
+        return true;
+    }
+
+    let mask = 0xFFu8 << (8 - remaining_bits);
+    (addr[full_bytes] & mask) == (prefix[full_bytes] & mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asn(number: u32, name: &str) -> Asn {
+        Asn {
+            number,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_asn_matches() {
+        let a = asn(15169, "GOOGLE");
+        assert!(a.matches("15169"));
+        assert!(a.matches("google"));
+        assert!(a.matches("GOO"));
+        assert!(!a.matches("16509"));
+        assert!(!a.matches(""));
+    }
+
+    #[test]
+    fn test_lookup_ipv4_picks_longest_prefix() {
+        let mut table = AsnTable::new();
+        table.insert_ipv4([8, 0, 0, 0], 8, asn(3356, "LEVEL3"));
+        table.insert_ipv4([8, 8, 8, 0], 24, asn(15169, "GOOGLE"));
+
+        let resolved = table
+            .lookup("8.8.8.8".parse().unwrap())
+            .expect("should resolve");
+        assert_eq!(resolved.number, 15169);
+
+        let fallback = table
+            .lookup("8.1.2.3".parse().unwrap())
+            .expect("should resolve to the shorter prefix");
+        assert_eq!(fallback.number, 3356);
+    }
+
+    #[test]
+    fn test_lookup_ipv4_no_match() {
+        let mut table = AsnTable::new();
+        table.insert_ipv4([10, 0, 0, 0], 8, asn(64512, "PRIVATE"));
+        assert!(table.lookup("192.168.1.1".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_parse_dataset_loads_well_formed_rows() {
+        let mut table = AsnTable::new();
+        table.parse_dataset("8.8.8.0/24,15169,GOOGLE\n1.1.1.0/24,13335,CLOUDFLARE\n");
+
+        assert_eq!(
+            table.lookup("8.8.8.8".parse().unwrap()).unwrap().number,
+            15169
+        );
+        assert_eq!(
+            table.lookup("1.1.1.1".parse().unwrap()).unwrap().number,
+            13335
+        );
+    }
+
+    #[test]
+    fn test_parse_dataset_skips_malformed_rows() {
+        let mut table = AsnTable::new();
+        table.parse_dataset("not,a,valid,row\n8.8.8.0/24,not-a-number,GOOGLE\n\n");
+        assert!(table.lookup("8.8.8.8".parse().unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_load_bundled_resolves_known_networks() {
+        let table = AsnTable::load_bundled();
+        assert_eq!(
+            table.lookup("8.8.8.8".parse().unwrap()).unwrap().number,
+            15169
+        );
+        assert_eq!(
+            table.lookup("1.1.1.1".parse().unwrap()).unwrap().number,
+            13335
+        );
+    }
+
+    #[test]
+    fn test_lookup_ipv6() {
+        let mut table = AsnTable::new();
+        table.insert_ipv6(
+            "2001:4860::".parse::<std::net::Ipv6Addr>().unwrap().octets(),
+            32,
+            asn(15169, "GOOGLE"),
+        );
+        let resolved = table
+            .lookup("2001:4860:4860::8888".parse().unwrap())
+            .expect("should resolve");
+        assert_eq!(resolved.number, 15169);
+    }
+}
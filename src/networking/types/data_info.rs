@@ -0,0 +1,146 @@
+//! Module defining the `DataInfo` struct, aggregating transmitted packets/bytes for a host,
+//! service, or process, keeping both a lifetime accumulated total and a current-window total.
+
+use std::cmp::Ordering;
+
+use crate::networking::types::data_representation::DataRepr;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::report::types::sort_type::SortType;
+use crate::report::types::view_mode::ViewMode;
+
+/// Packet/byte counters split by direction.
+#[derive(Clone, Copy, Debug, Default)]
+struct Counters {
+    incoming_packets: u128,
+    outgoing_packets: u128,
+    incoming_bytes: u128,
+    outgoing_bytes: u128,
+}
+
+impl Counters {
+    fn add(&mut self, packets: u128, bytes: u128, traffic_direction: TrafficDirection) {
+        match traffic_direction {
+            TrafficDirection::Incoming => {
+                self.incoming_packets += packets;
+                self.incoming_bytes += bytes;
+            }
+            TrafficDirection::Outgoing => {
+                self.outgoing_packets += packets;
+                self.outgoing_bytes += bytes;
+            }
+        }
+    }
+
+    fn tot_packets(&self) -> u128 {
+        self.incoming_packets + self.outgoing_packets
+    }
+
+    fn tot_bytes(&self) -> u128 {
+        self.incoming_bytes + self.outgoing_bytes
+    }
+}
+
+/// Aggregated packet/byte counters for a host, service, or process, keeping both the lifetime
+/// accumulated total and the total within the current observation window side by side. See
+/// [`ViewMode`] for which of the two a given report reflects.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DataInfo {
+    accumulated: Counters,
+    current: Counters,
+}
+
+impl DataInfo {
+    /// Records `packets`/`bytes` into both the accumulated and current-window counters.
+    pub fn add_packets(&mut self, packets: u128, bytes: u128, traffic_direction: TrafficDirection) {
+        self.accumulated.add(packets, bytes, traffic_direction);
+        self.current.add(packets, bytes, traffic_direction);
+    }
+
+    /// Clears the current-window counters, keeping the accumulated total untouched. Called at
+    /// the start of each new observation window.
+    pub fn reset_current_window(&mut self) {
+        self.current = Counters::default();
+    }
+
+    fn counters(&self, view_mode: ViewMode) -> &Counters {
+        match view_mode {
+            ViewMode::Accumulated => &self.accumulated,
+            ViewMode::Current => &self.current,
+        }
+    }
+
+    pub fn tot_packets(&self, view_mode: ViewMode) -> u128 {
+        self.counters(view_mode).tot_packets()
+    }
+
+    pub fn tot_bytes(&self, view_mode: ViewMode) -> u128 {
+        self.counters(view_mode).tot_bytes()
+    }
+
+    pub fn transmitted_data(&self, data_repr: DataRepr, view_mode: ViewMode) -> u128 {
+        match data_repr {
+            DataRepr::Packets => self.tot_packets(view_mode),
+            DataRepr::Bytes => self.tot_bytes(view_mode),
+            DataRepr::Bits => self.tot_bytes(view_mode) * 8,
+        }
+    }
+
+    pub fn compare(
+        &self,
+        other: &Self,
+        sort_type: SortType,
+        data_repr: DataRepr,
+        view_mode: ViewMode,
+    ) -> Ordering {
+        match sort_type {
+            SortType::Ascending => self
+                .transmitted_data(data_repr, view_mode)
+                .cmp(&other.transmitted_data(data_repr, view_mode)),
+            SortType::Descending => other
+                .transmitted_data(data_repr, view_mode)
+                .cmp(&self.transmitted_data(data_repr, view_mode)),
+            SortType::Neutral => Ordering::Equal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulated_survives_window_reset_but_current_does_not() {
+        let mut data = DataInfo::default();
+        data.add_packets(10, 1000, TrafficDirection::Outgoing);
+        data.reset_current_window();
+        data.add_packets(4, 400, TrafficDirection::Outgoing);
+
+        assert_eq!(data.tot_packets(ViewMode::Accumulated), 14);
+        assert_eq!(data.tot_bytes(ViewMode::Accumulated), 1400);
+        assert_eq!(data.tot_packets(ViewMode::Current), 4);
+        assert_eq!(data.tot_bytes(ViewMode::Current), 400);
+    }
+
+    #[test]
+    fn test_compare_respects_view_mode() {
+        let mut a = DataInfo::default();
+        a.add_packets(100, 10_000, TrafficDirection::Outgoing);
+        a.reset_current_window();
+        a.add_packets(1, 10, TrafficDirection::Outgoing);
+
+        let mut b = DataInfo::default();
+        b.add_packets(1, 10, TrafficDirection::Outgoing);
+        b.reset_current_window();
+        b.add_packets(50, 5_000, TrafficDirection::Outgoing);
+
+        // `a` has the larger accumulated total, `b` has the larger current-window total.
+        assert_eq!(
+            a.compare(&b, SortType::Ascending, DataRepr::Bytes, ViewMode::Accumulated),
+            Ordering::Greater
+        );
+        assert_eq!(
+            a.compare(&b, SortType::Ascending, DataRepr::Bytes, ViewMode::Current),
+            Ordering::Less
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Module defining the `DataInfoHost` struct, pairing a host's [`DataInfo`] with the report-only
+//! flags that apply to it.
+
+use crate::networking::types::data_info::DataInfo;
+
+/// A host's aggregated traffic data, plus whether the user has starred it as a favorite.
+#[derive(Clone, Debug, Default)]
+pub struct DataInfoHost {
+    pub data_info: DataInfo,
+    pub is_favorite: bool,
+}
@@ -0,0 +1,104 @@
+//! Module defining the `Host` struct, representing a remote host resolved from an `IpAddr`.
+
+use std::cmp::Ordering;
+use std::net::IpAddr;
+use std::sync::OnceLock;
+
+use crate::networking::types::asn::{Asn, AsnTable};
+use crate::report::types::sort_type::SortType;
+
+/// Process-wide [`AsnTable`] backing [`Host::resolve`], loaded once from the bundled
+/// IP-to-ASN dataset the first time a host needs resolving.
+static BUNDLED_ASN_TABLE: OnceLock<AsnTable> = OnceLock::new();
+
+/// Remote host resolved from an `IpAddr`, carrying its rDNS domain and its origin ASN.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Host {
+    /// Resolved rDNS domain.
+    pub domain: String,
+    /// Origin autonomous system, resolved via the bundled IP-to-ASN table.
+    pub asn: Asn,
+}
+
+impl Host {
+    /// Builds a `Host` for `ip`, resolving its origin ASN from `asn_table`.
+    pub fn new(domain: String, ip: IpAddr, asn_table: &AsnTable) -> Self {
+        Self {
+            domain,
+            asn: asn_table.lookup(ip).unwrap_or_default(),
+        }
+    }
+
+    /// Builds a `Host` for `ip` as it resolves during rDNS resolution in production: against the
+    /// process-wide [`AsnTable`], loaded once from the bundled IP-to-ASN dataset.
+    pub fn resolve(domain: String, ip: IpAddr) -> Self {
+        let asn_table = BUNDLED_ASN_TABLE.get_or_init(AsnTable::load_bundled);
+        Self::new(domain, ip, asn_table)
+    }
+
+    /// Compares two hosts by ASN number, used to sort the host report by [`SortBy::Asn`](crate::report::types::sort_by::SortBy::Asn).
+    pub fn compare_by_asn(&self, other: &Self, sort_type: SortType) -> Ordering {
+        match sort_type {
+            SortType::Ascending => self.asn.number.cmp(&other.asn.number),
+            SortType::Descending => other.asn.number.cmp(&self.asn.number),
+            SortType::Neutral => Ordering::Equal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_new_resolves_asn_from_table() {
+        let mut table = AsnTable::new();
+        table.insert_ipv4(
+            [8, 8, 8, 0],
+            24,
+            Asn {
+                number: 15169,
+                name: "GOOGLE".to_string(),
+            },
+        );
+
+        let host = Host::new("dns.google".to_string(), "8.8.8.8".parse().unwrap(), &table);
+        assert_eq!(host.asn.number, 15169);
+        assert_eq!(host.domain, "dns.google");
+    }
+
+    #[test]
+    fn test_host_new_defaults_asn_when_unresolved() {
+        let table = AsnTable::new();
+        let host = Host::new("unknown".to_string(), "203.0.113.1".parse().unwrap(), &table);
+        assert_eq!(host.asn, Asn::default());
+    }
+
+    #[test]
+    fn test_resolve_uses_bundled_dataset() {
+        let host = Host::resolve("dns.google".to_string(), "8.8.8.8".parse().unwrap());
+        assert_eq!(host.asn.number, 15169);
+        assert_eq!(host.domain, "dns.google");
+    }
+
+    #[test]
+    fn test_compare_by_asn() {
+        let a = Host {
+            domain: "a".to_string(),
+            asn: Asn {
+                number: 10,
+                name: "A".to_string(),
+            },
+        };
+        let b = Host {
+            domain: "b".to_string(),
+            asn: Asn {
+                number: 20,
+                name: "B".to_string(),
+            },
+        };
+        assert_eq!(a.compare_by_asn(&b, SortType::Ascending), Ordering::Less);
+        assert_eq!(a.compare_by_asn(&b, SortType::Descending), Ordering::Greater);
+        assert_eq!(a.compare_by_asn(&b, SortType::Neutral), Ordering::Equal);
+    }
+}
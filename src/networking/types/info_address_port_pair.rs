@@ -2,12 +2,13 @@
 //! to keep track of statistics about the sniffed traffic.
 
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use crate::Service;
 use crate::networking::types::arp_type::ArpType;
 use crate::networking::types::data_representation::DataRepr;
 use crate::networking::types::icmp_type::IcmpType;
+use crate::networking::types::process_info::ProcessInfo;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::report::types::sort_by::SortBy;
 use crate::report::types::sort_type::SortType;
@@ -42,8 +43,16 @@ pub struct InfoAddressPortPair {
     pub latency: Option<i64>,
     /// Information about the SYN packet of this connection.
     pub syn_info: Option<(Timestamp, TrafficDirection)>,
+    /// Local process owning this connection's local socket, resolved from the OS.
+    pub process: Option<ProcessInfo>,
+    /// Last [`RATE_WINDOW_LEN`] refresh samples of `(timestamp, transmitted_bytes, transmitted_packets)`,
+    /// used to compute an instantaneous, rather than cumulative, throughput rate.
+    pub rate_samples: VecDeque<(Timestamp, u128, u128)>,
 }
 
+/// Number of refresh samples kept to compute the instantaneous throughput rate.
+const RATE_WINDOW_LEN: usize = 5;
+
 impl InfoAddressPortPair {
     pub fn refresh(&mut self, other: &Self) {
         self.transmitted_bytes += other.transmitted_bytes;
@@ -69,6 +78,17 @@ impl InfoAddressPortPair {
         if other.syn_info.is_some() {
             self.syn_info = other.syn_info;
         }
+        if other.process.is_some() {
+            self.process = other.process.clone();
+        }
+        self.rate_samples.push_back((
+            self.final_timestamp,
+            self.transmitted_bytes,
+            self.transmitted_packets,
+        ));
+        while self.rate_samples.len() > RATE_WINDOW_LEN {
+            self.rate_samples.pop_front();
+        }
     }
 
     pub fn transmitted_data(&self, data_repr: DataRepr) -> u128 {
@@ -79,23 +99,63 @@ impl InfoAddressPortPair {
         }
     }
 
+    /// Returns the instantaneous throughput rate (per second) over the last
+    /// [`RATE_WINDOW_LEN`] refresh samples, rather than the lifetime average.
+    pub fn current_rate(&self, data_repr: DataRepr) -> f64 {
+        let (Some(&(oldest_ts, oldest_bytes, oldest_packets)), Some(&(newest_ts, newest_bytes, newest_packets))) =
+            (self.rate_samples.front(), self.rate_samples.back())
+        else {
+            return 0.0;
+        };
+
+        let elapsed_secs = newest_ts - oldest_ts;
+        if elapsed_secs <= 0.0 {
+            return 0.0;
+        }
+
+        let delta = match data_repr {
+            DataRepr::Packets => (newest_packets - oldest_packets) as f64,
+            DataRepr::Bytes => (newest_bytes - oldest_bytes) as f64,
+            DataRepr::Bits => ((newest_bytes - oldest_bytes) * 8) as f64,
+        };
+        delta / elapsed_secs
+    }
+
+    /// Returns the owning process name of this pair, if resolved, used to sort by
+    /// [`SortBy::Process`].
+    fn process_name(&self) -> Option<&str> {
+        self.process.as_ref().map(|process| process.name.as_str())
+    }
+
     pub fn compare(
         &self,
         other: &Self,
         sort_by: SortBy,
         sort_type: SortType,
-        _data_repr: DataRepr,
+        data_repr: DataRepr,
     ) -> Ordering {
         match sort_type {
             SortType::Ascending => match sort_by {
                 SortBy::Packets => self.transmitted_packets.cmp(&other.transmitted_packets),
                 SortBy::Bytes => self.transmitted_bytes.cmp(&other.transmitted_bytes),
                 SortBy::Latency => self.latency.cmp(&other.latency),
+                // ASN is host-level (see `Host::compare_by_asn`); the per-pair report has no
+                // opinion on it.
+                SortBy::Asn => Ordering::Equal,
+                SortBy::Process => self.process_name().cmp(&other.process_name()),
+                SortBy::Rate => self
+                    .current_rate(data_repr)
+                    .total_cmp(&other.current_rate(data_repr)),
             },
             SortType::Descending => match sort_by {
                 SortBy::Packets => other.transmitted_packets.cmp(&self.transmitted_packets),
                 SortBy::Bytes => other.transmitted_bytes.cmp(&self.transmitted_bytes),
                 SortBy::Latency => other.latency.cmp(&self.latency),
+                SortBy::Asn => Ordering::Equal,
+                SortBy::Process => other.process_name().cmp(&self.process_name()),
+                SortBy::Rate => other
+                    .current_rate(data_repr)
+                    .total_cmp(&self.current_rate(data_repr)),
             },
             SortType::Neutral => other.final_timestamp.cmp(&self.final_timestamp),
         }
@@ -201,4 +261,44 @@ mod tests {
             Ordering::Greater
         );
     }
+
+    #[test]
+    fn test_current_rate() {
+        let mut pair = InfoAddressPortPair::default();
+
+        let mut sample = InfoAddressPortPair {
+            transmitted_bytes: 1000,
+            transmitted_packets: 10,
+            final_timestamp: Timestamp::new(0, 0),
+            ..Default::default()
+        };
+        pair.refresh(&sample);
+
+        sample.transmitted_bytes = 1000;
+        sample.transmitted_packets = 10;
+        sample.final_timestamp = Timestamp::new(2, 0);
+        pair.refresh(&sample);
+
+        // Rate samples store the *cumulative* total at each refresh: 1000 bytes/10 packets at
+        // t=0, then 2000 bytes/20 packets at t=2. The delta over the window is (2000-1000) bytes
+        // and (20-10) packets over 2 seconds, i.e. 500 bytes/s and 5 packets/s.
+        assert_eq!(pair.current_rate(DataRepr::Bytes), 500.0);
+        assert_eq!(pair.current_rate(DataRepr::Packets), 5.0);
+        assert_eq!(pair.current_rate(DataRepr::Bits), 4000.0);
+    }
+
+    #[test]
+    fn test_rate_samples_window_is_capped() {
+        let mut pair = InfoAddressPortPair::default();
+        for secs in 0..10 {
+            let sample = InfoAddressPortPair {
+                transmitted_bytes: 100,
+                transmitted_packets: 1,
+                final_timestamp: Timestamp::new(secs, 0),
+                ..Default::default()
+            };
+            pair.refresh(&sample);
+        }
+        assert_eq!(pair.rate_samples.len(), RATE_WINDOW_LEN);
+    }
 }
@@ -0,0 +1,194 @@
+//! Module defining the `NeighborCache`, a standalone IP↔MAC neighbor table folded from observed
+//! ARP/NDP exchanges, independent of the connection map so it can expire its own entries.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use crate::utils::types::timestamp::Timestamp;
+
+/// Default time-to-live of a neighbor cache entry since its last sighting.
+pub const DEFAULT_NEIGHBOR_TTL_SECS: i64 = 60;
+
+/// A single neighbor sighting: the MAC address currently claiming an IP, and when that claim
+/// expires if not refreshed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Neighbor {
+    /// MAC address observed for the owning `IpAddr`.
+    pub mac: String,
+    /// Timestamp of the last sighting of this MAC for the owning `IpAddr`.
+    pub last_seen: Timestamp,
+    /// Timestamp at which this entry is evicted if not refreshed.
+    pub expires_at: Timestamp,
+}
+
+/// A potential ARP-spoofing indicator: the same IP claimed by more than one MAC within the TTL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighborConflict {
+    pub ip: IpAddr,
+    pub macs: Vec<String>,
+}
+
+/// Standalone cache folding observed ARP/NDP exchanges into a map from [`IpAddr`] to its current
+/// neighbor, updated from the packet-processing path and evicted independently of the
+/// connection map via a configurable TTL (default [`DEFAULT_NEIGHBOR_TTL_SECS`]).
+#[derive(Debug)]
+pub struct NeighborCache {
+    neighbors: HashMap<IpAddr, Neighbor>,
+    /// Last-seen timestamp of every distinct MAC observed for each IP, used to flag IP→MAC
+    /// conflicts; each MAC is pruned individually once its own TTL lapses, so a conflict never
+    /// outlives the window in which both MACs were actually seen.
+    recent_macs: HashMap<IpAddr, Vec<(String, Timestamp)>>,
+    ttl_secs: i64,
+}
+
+impl Default for NeighborCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_NEIGHBOR_TTL_SECS)
+    }
+}
+
+impl NeighborCache {
+    pub fn new(ttl_secs: i64) -> Self {
+        Self {
+            neighbors: HashMap::new(),
+            recent_macs: HashMap::new(),
+            ttl_secs,
+        }
+    }
+
+    /// Folds an observed ARP/NDP exchange into the cache, refreshing `ip`'s expiration and
+    /// coalescing repeated sightings of the same MAC.
+    pub fn observe(&mut self, ip: IpAddr, mac: String, now: Timestamp) {
+        let expires_at = now.saturating_add_secs(self.ttl_secs);
+
+        let macs_for_ip = self.recent_macs.entry(ip).or_default();
+        if let Some(entry) = macs_for_ip.iter_mut().find(|(seen_mac, _)| *seen_mac == mac) {
+            entry.1 = now;
+        } else {
+            macs_for_ip.push((mac.clone(), now));
+        }
+
+        self.neighbors.insert(
+            ip,
+            Neighbor {
+                mac,
+                last_seen: now,
+                expires_at,
+            },
+        );
+    }
+
+    /// Evicts every neighbor whose entry has expired as of `now`, and prunes any individual MAC
+    /// sighting older than the TTL so conflicts don't outlive the window in which they occurred.
+    /// Should be called periodically from the packet-processing path, independently of
+    /// connection-map eviction.
+    pub fn evict_expired(&mut self, now: Timestamp) {
+        self.neighbors.retain(|_, neighbor| neighbor.expires_at > now);
+
+        self.recent_macs.retain(|_, macs| {
+            macs.retain(|(_, last_seen)| last_seen.saturating_add_secs(self.ttl_secs) > now);
+            !macs.is_empty()
+        });
+    }
+
+    /// Returns the current neighbors, each paired with its age (`now - last_seen`).
+    pub fn current_neighbors(&self, now: Timestamp) -> Vec<(IpAddr, Neighbor, i64)> {
+        self.neighbors
+            .iter()
+            .map(|(ip, neighbor)| {
+                let age_secs = now.secs_since(neighbor.last_seen);
+                (*ip, neighbor.clone(), age_secs)
+            })
+            .collect()
+    }
+
+    /// Returns every IP that has been claimed by more than one distinct MAC within the TTL,
+    /// a potential ARP-spoofing indicator.
+    pub fn conflicts(&self) -> Vec<NeighborConflict> {
+        self.recent_macs
+            .iter()
+            .filter(|(_, macs)| macs.len() > 1)
+            .map(|(ip, macs)| NeighborConflict {
+                ip: *ip,
+                macs: macs.iter().map(|(mac, _)| mac.clone()).collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_observe_and_lookup() {
+        let mut cache = NeighborCache::new(60);
+        let now = Timestamp::new(0, 0);
+        cache.observe(ip("192.168.1.1"), "aa:bb:cc:dd:ee:ff".to_string(), now);
+
+        let neighbors = cache.current_neighbors(now);
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].0, ip("192.168.1.1"));
+        assert_eq!(neighbors[0].1.mac, "aa:bb:cc:dd:ee:ff");
+        assert_eq!(neighbors[0].2, 0);
+    }
+
+    #[test]
+    fn test_eviction_after_ttl() {
+        let mut cache = NeighborCache::new(60);
+        cache.observe(
+            ip("192.168.1.1"),
+            "aa:bb:cc:dd:ee:ff".to_string(),
+            Timestamp::new(0, 0),
+        );
+
+        cache.evict_expired(Timestamp::new(30, 0));
+        assert_eq!(cache.current_neighbors(Timestamp::new(30, 0)).len(), 1);
+
+        cache.evict_expired(Timestamp::new(61, 0));
+        assert_eq!(cache.current_neighbors(Timestamp::new(61, 0)).len(), 0);
+    }
+
+    #[test]
+    fn test_flapping_mac_detected_as_conflict() {
+        let mut cache = NeighborCache::new(60);
+        let ip1 = ip("192.168.1.1");
+        cache.observe(ip1, "aa:aa:aa:aa:aa:aa".to_string(), Timestamp::new(0, 0));
+        cache.observe(ip1, "bb:bb:bb:bb:bb:bb".to_string(), Timestamp::new(1, 0));
+
+        let conflicts = cache.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].ip, ip1);
+        assert_eq!(conflicts[0].macs.len(), 2);
+    }
+
+    #[test]
+    fn test_conflict_clears_once_stale_mac_expires() {
+        let mut cache = NeighborCache::new(60);
+        let ip1 = ip("192.168.1.1");
+        cache.observe(ip1, "aa:aa:aa:aa:aa:aa".to_string(), Timestamp::new(0, 0));
+        cache.observe(ip1, "bb:bb:bb:bb:bb:bb".to_string(), Timestamp::new(30, 0));
+        assert_eq!(cache.conflicts().len(), 1);
+
+        // `bb` keeps refreshing, but `aa` hasn't been seen in over a TTL: its sighting should
+        // be pruned even though the IP's neighbor entry (tracking `bb`) is still alive.
+        cache.observe(ip1, "bb:bb:bb:bb:bb:bb".to_string(), Timestamp::new(100, 0));
+        cache.evict_expired(Timestamp::new(100, 0));
+
+        assert!(cache.conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_repeated_sighting_of_same_mac_is_not_a_conflict() {
+        let mut cache = NeighborCache::new(60);
+        let ip1 = ip("192.168.1.1");
+        cache.observe(ip1, "aa:aa:aa:aa:aa:aa".to_string(), Timestamp::new(0, 0));
+        cache.observe(ip1, "aa:aa:aa:aa:aa:aa".to_string(), Timestamp::new(1, 0));
+
+        assert!(cache.conflicts().is_empty());
+    }
+}
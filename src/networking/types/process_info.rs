@@ -0,0 +1,229 @@
+//! Module defining the `ProcessInfo` struct, used to attribute a local socket to the owning
+//! process, and `ProcessResolver`, the platform-specific lookup used to populate it.
+
+use crate::networking::types::trans_protocol::TransProtocol;
+
+/// Owning process of a local socket (the local `address:port` of an `AddressPortPair`).
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessInfo {
+    /// Process name (e.g. `firefox`).
+    pub name: String,
+    /// Process identifier.
+    pub pid: u32,
+}
+
+impl ProcessInfo {
+    /// Returns `true` if `query` (case-insensitive) matches this process name or PID, used by
+    /// the report search bar.
+    pub fn matches(&self, query: &str) -> bool {
+        if query.is_empty() {
+            return false;
+        }
+        if let Ok(pid) = query.parse::<u32>() {
+            if pid == self.pid {
+                return true;
+            }
+        }
+        self.name.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// Resolves the local process owning a socket, by `(transport protocol, local port)` — TCP and
+/// UDP each have their own port namespace, so port alone is not a unique key (e.g. TCP port 53
+/// and UDP port 53 are routinely different processes).
+///
+/// A single [`ProcessResolver::refresh`] call reads every socket table and walks every process's
+/// open file descriptors *once*, building a complete `(protocol, local_port) -> ProcessInfo` map;
+/// each connection is then resolved with a plain hash-map lookup via [`ProcessResolver::resolve`].
+/// This keeps a report refresh at `O(sockets + processes)` regardless of connection count,
+/// instead of repeating that whole scan per connection.
+#[derive(Default, Debug)]
+pub struct ProcessResolver {
+    port_to_process: std::collections::HashMap<(TransProtocol, u16), ProcessInfo>,
+}
+
+impl ProcessResolver {
+    /// Rebuilds the `(protocol, local_port) -> ProcessInfo` map from the current OS state. Call
+    /// this once per report refresh, before resolving any connection. Only Linux is scanned; on
+    /// other platforms the map is left empty, so every [`ProcessResolver::resolve`] misses.
+    pub fn refresh(&mut self) {
+        #[cfg(target_os = "linux")]
+        {
+            self.port_to_process = linux::scan_port_to_process();
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            self.port_to_process.clear();
+        }
+    }
+
+    /// Returns the process owning `(protocol, local_port)`, as of the last
+    /// [`ProcessResolver::refresh`].
+    pub fn resolve(&self, protocol: TransProtocol, local_port: u16) -> Option<ProcessInfo> {
+        self.port_to_process.get(&(protocol, local_port)).cloned()
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::ProcessInfo;
+    use crate::networking::types::trans_protocol::TransProtocol;
+    use std::collections::HashMap;
+    use std::fs;
+
+    /// Builds the complete `(protocol, local_port) -> ProcessInfo` map in two passes: first
+    /// mapping every socket inode to its `(protocol, local_port)` from
+    /// `/proc/net/{tcp,tcp6,udp,udp6}`, then mapping every process's open socket file descriptors
+    /// (via `/proc/<pid>/fd`) to that same inode.
+    pub fn scan_port_to_process() -> HashMap<(TransProtocol, u16), ProcessInfo> {
+        let port_by_inode = scan_proc_net_tables();
+        let process_by_inode = scan_proc_processes();
+
+        port_by_inode
+            .into_iter()
+            .filter_map(|(inode, key)| {
+                process_by_inode
+                    .get(&inode)
+                    .map(|process| (key, process.clone()))
+            })
+            .collect()
+    }
+
+    /// Parses `/proc/net/{tcp,tcp6,udp,udp6}` once, returning every socket inode found, keyed to
+    /// its `(protocol, local port)` — TCP and UDP tables are scanned separately so the two port
+    /// namespaces are never conflated.
+    fn scan_proc_net_tables() -> HashMap<u64, (TransProtocol, u16)> {
+        let mut port_by_inode = HashMap::new();
+
+        let tables = [
+            ("/proc/net/tcp", TransProtocol::TCP),
+            ("/proc/net/tcp6", TransProtocol::TCP),
+            ("/proc/net/udp", TransProtocol::UDP),
+            ("/proc/net/udp6", TransProtocol::UDP),
+        ];
+        for (table, protocol) in tables {
+            let Ok(contents) = fs::read_to_string(table) else {
+                continue;
+            };
+            for line in contents.lines().skip(1) {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let Some(local_address) = fields.get(1) else {
+                    continue;
+                };
+                let Some(port_hex) = local_address.rsplit(':').next() else {
+                    continue;
+                };
+                let Ok(port) = u16::from_str_radix(port_hex, 16) else {
+                    continue;
+                };
+                if let Some(inode_str) = fields.get(9) {
+                    if let Ok(inode) = inode_str.parse::<u64>() {
+                        port_by_inode.insert(inode, (protocol, port));
+                    }
+                }
+            }
+        }
+        port_by_inode
+    }
+
+    /// Walks `/proc/<pid>/fd` for every running process once, returning every open socket inode
+    /// found, keyed to the process that holds it.
+    fn scan_proc_processes() -> HashMap<u64, ProcessInfo> {
+        let mut process_by_inode = HashMap::new();
+
+        let Ok(proc_dir) = fs::read_dir("/proc") else {
+            return process_by_inode;
+        };
+        for entry in proc_dir.flatten() {
+            let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+                continue;
+            };
+            let fd_dir = format!("/proc/{pid}/fd");
+            let Ok(fds) = fs::read_dir(&fd_dir) else {
+                continue;
+            };
+
+            let mut name: Option<String> = None;
+            for fd in fds.flatten() {
+                let Ok(link) = fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let link = link.to_string_lossy();
+                let Some(inode) = link
+                    .strip_prefix("socket:[")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse::<u64>().ok())
+                else {
+                    continue;
+                };
+
+                let name = name.get_or_insert_with(|| {
+                    fs::read_to_string(format!("/proc/{pid}/comm"))
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string()
+                });
+                process_by_inode.insert(
+                    inode,
+                    ProcessInfo {
+                        name: name.clone(),
+                        pid,
+                    },
+                );
+            }
+        }
+        process_by_inode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_info_matches() {
+        let p = ProcessInfo {
+            name: "firefox".to_string(),
+            pid: 4242,
+        };
+        assert!(p.matches("4242"));
+        assert!(p.matches("fire"));
+        assert!(p.matches("FIREFOX"));
+        assert!(!p.matches("1234"));
+        assert!(!p.matches(""));
+    }
+
+    #[test]
+    fn test_resolver_misses_before_refresh() {
+        let resolver = ProcessResolver::default();
+        assert_eq!(resolver.resolve(TransProtocol::TCP, 443), None);
+    }
+
+    #[test]
+    fn test_resolver_keeps_tcp_and_udp_namespaces_distinct() {
+        let mut resolver = ProcessResolver::default();
+        resolver.port_to_process.insert(
+            (TransProtocol::TCP, 53),
+            ProcessInfo {
+                name: "bind9".to_string(),
+                pid: 100,
+            },
+        );
+        resolver.port_to_process.insert(
+            (TransProtocol::UDP, 53),
+            ProcessInfo {
+                name: "unbound".to_string(),
+                pid: 200,
+            },
+        );
+
+        assert_eq!(
+            resolver.resolve(TransProtocol::TCP, 53).unwrap().name,
+            "bind9"
+        );
+        assert_eq!(
+            resolver.resolve(TransProtocol::UDP, 53).unwrap().name,
+            "unbound"
+        );
+    }
+}
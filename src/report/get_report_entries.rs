@@ -1,21 +1,48 @@
 use std::cmp::min;
+use std::collections::HashMap;
+use std::net::IpAddr;
 
 use crate::networking::manage_packets::get_address_to_lookup;
+use crate::networking::manage_packets::get_local_port;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::data_representation::DataRepr;
 use crate::networking::types::host::Host;
+use crate::networking::types::neighbor_cache::{Neighbor, NeighborConflict};
+use crate::networking::types::process_info::{ProcessInfo, ProcessResolver};
 use crate::report::types::report_entry::ReportEntry;
+use crate::report::types::sort_by::SortBy;
 use crate::report::types::sort_type::SortType;
+use crate::report::types::view_mode::ViewMode;
+use crate::utils::types::timestamp::Timestamp;
 use crate::{InfoTraffic, Service, Sniffer};
-use std::net::IpAddr;
 
 /// Return the elements that satisfy the search constraints and belong to the given page,
 /// and the total number of elements which satisfy the search constraints,
 /// with their packets, in-bytes, and out-bytes count
-pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize, DataInfo) {
+pub fn get_searched_entries(
+    sniffer: &mut Sniffer,
+    now: Timestamp,
+) -> (Vec<ReportEntry>, usize, DataInfo) {
+    // Evict stale neighbor-cache entries once per report refresh, independently of the
+    // connection map below.
+    sniffer.info_traffic.neighbors.evict_expired(now);
+
+    // One full OS scan per report refresh, reused for every connection below, rather than
+    // repeating the scan per connection (see `ProcessResolver`), and persisted onto the stored
+    // `InfoAddressPortPair` so it only needs to be resolved once per connection, ever.
+    let mut process_resolver = ProcessResolver::default();
+    process_resolver.refresh();
+    for (key, value) in sniffer.info_traffic.map.iter_mut() {
+        if value.process.is_none() {
+            let (protocol, local_port) = get_local_port(key, value.traffic_direction);
+            value.process = process_resolver.resolve(protocol, local_port);
+        }
+    }
+
     let mut agglomerate = DataInfo::default();
     let info_traffic = &sniffer.info_traffic;
+
     let mut all_results: Vec<ReportEntry> = info_traffic
         .map
         .iter()
@@ -48,6 +75,7 @@ pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize, Data
             if !is_blacklisted && sniffer.blacklist.contains(&address_port_pair.address2) {
                 is_blacklisted = true;
             }
+
             ReportEntry {
                 key: address_port_pair.clone(),
                 val: val.clone(),
@@ -59,31 +87,54 @@ pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize, Data
     all_results.sort_by(|a, b| {
         a.val.compare(
             &b.val,
+            sniffer.conf.report_sort_by,
             sniffer.conf.report_sort_type,
             sniffer.traffic_chart.data_repr,
         )
     });
 
     let upper_bound = min(sniffer.page_number * 20, all_results.len());
+    let page = all_results
+        .get((sniffer.page_number.saturating_sub(1)) * 20..upper_bound)
+        .unwrap_or_default()
+        .to_vec();
+    let total = all_results.len();
 
-    (
-        all_results
-            .get((sniffer.page_number.saturating_sub(1)) * 20..upper_bound)
-            .unwrap_or_default()
-            .to_vec(),
-        all_results.len(),
-        agglomerate,
-    )
+    // This report refresh's current-window activity has now been read (above, and by
+    // `get_host_entries`/`get_service_entries`); roll the window so the next refresh's
+    // `ViewMode::Current` totals start counting from zero again.
+    for data_info_host in sniffer.info_traffic.hosts.values_mut() {
+        data_info_host.data_info.reset_current_window();
+    }
+    for data_info in sniffer.info_traffic.services.values_mut() {
+        data_info.reset_current_window();
+    }
+
+    (page, total, agglomerate)
 }
 
+/// Returns the top 30 hosts by transmitted data.
+///
+/// `view_mode` selects whether the returned [`DataInfoHost`] (and the sort order applied to it)
+/// reflects the lifetime accumulated total or only the activity within the current observation
+/// window. `sort_by` additionally allows sorting by a host-level field (currently only
+/// [`SortBy::Asn`](crate::report::types::sort_by::SortBy::Asn)) instead of by traffic volume.
 pub fn get_host_entries(
     info_traffic: &InfoTraffic,
     data_repr: DataRepr,
     sort_type: SortType,
+    sort_by: SortBy,
+    view_mode: ViewMode,
 ) -> Vec<(Host, DataInfoHost)> {
     let mut sorted_vec: Vec<(&Host, &DataInfoHost)> = info_traffic.hosts.iter().collect();
 
-    sorted_vec.sort_by(|&(_, a), &(_, b)| a.data_info.compare(&b.data_info, sort_type, data_repr));
+    sorted_vec.sort_by(|&(host_a, a), &(host_b, b)| {
+        if sort_by == SortBy::Asn {
+            host_a.compare_by_asn(host_b, sort_type)
+        } else {
+            a.data_info.compare(&b.data_info, sort_type, data_repr, view_mode)
+        }
+    });
 
     let n_entry = min(sorted_vec.len(), 30);
     sorted_vec[0..n_entry]
@@ -92,10 +143,12 @@ pub fn get_host_entries(
         .collect()
 }
 
+/// Returns the top 30 services by transmitted data. See [`get_host_entries`] for `view_mode`.
 pub fn get_service_entries(
     info_traffic: &InfoTraffic,
     data_repr: DataRepr,
     sort_type: SortType,
+    view_mode: ViewMode,
 ) -> Vec<(Service, DataInfo)> {
     let mut sorted_vec: Vec<(&Service, &DataInfo)> = info_traffic
         .services
@@ -103,7 +156,7 @@ pub fn get_service_entries(
         .filter(|(service, _)| service != &&Service::NotApplicable)
         .collect();
 
-    sorted_vec.sort_by(|&(_, a), &(_, b)| a.compare(b, sort_type, data_repr));
+    sorted_vec.sort_by(|&(_, a), &(_, b)| a.compare(b, sort_type, data_repr, view_mode));
 
     let n_entry = min(sorted_vec.len(), 30);
     sorted_vec[0..n_entry]
@@ -111,3 +164,46 @@ pub fn get_service_entries(
         .map(|&(service, data_info)| (*service, *data_info))
         .collect()
 }
+
+/// Returns the top 30 local processes by transmitted data, analogous to [`get_service_entries`]
+/// but grouped by the process attributed to each connection's local socket (folded on the fly
+/// from `info_traffic.map`, the same per-connection data `get_searched_entries` resolves
+/// processes against). The per-connection totals folded here are lifetime totals, so both
+/// `view_mode`s return the same values; only the lifetime accumulated total is available.
+pub fn get_process_entries(
+    info_traffic: &InfoTraffic,
+    data_repr: DataRepr,
+    sort_type: SortType,
+    view_mode: ViewMode,
+) -> Vec<(ProcessInfo, DataInfo)> {
+    let mut by_process: HashMap<ProcessInfo, DataInfo> = HashMap::new();
+    for val in info_traffic.map.values() {
+        let Some(process) = &val.process else {
+            continue;
+        };
+        by_process.entry(process.clone()).or_default().add_packets(
+            val.transmitted_packets,
+            val.transmitted_bytes,
+            val.traffic_direction,
+        );
+    }
+
+    let mut sorted_vec: Vec<(ProcessInfo, DataInfo)> = by_process.into_iter().collect();
+    sorted_vec.sort_by(|(_, a), (_, b)| a.compare(b, sort_type, data_repr, view_mode));
+    sorted_vec.truncate(30);
+    sorted_vec
+}
+
+/// Returns the current ARP/NDP neighbor table (IP, neighbor, age in seconds) and any IP→MAC
+/// conflicts observed within the cache's TTL, the "neighbor view" report section. `now` should be
+/// the same timestamp passed to the [`get_searched_entries`] call this refresh, so conflicts are
+/// read against the entries that refresh just evicted.
+pub fn get_neighbor_entries(
+    info_traffic: &InfoTraffic,
+    now: Timestamp,
+) -> (Vec<(IpAddr, Neighbor, i64)>, Vec<NeighborConflict>) {
+    (
+        info_traffic.neighbors.current_neighbors(now),
+        info_traffic.neighbors.conflicts(),
+    )
+}
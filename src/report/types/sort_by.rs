@@ -6,4 +6,7 @@ pub enum SortBy {
     Packets,
     Bytes,
     Latency,
+    Asn,
+    Process,
+    Rate,
 }
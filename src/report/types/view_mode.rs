@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Selects whether an aggregated report (hosts/services) displays the lifetime total or only
+/// the activity observed within the current refresh window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ViewMode {
+    #[default]
+    Accumulated,
+    Current,
+}